@@ -2,8 +2,8 @@
 //!
 //! A library for modeling artistic concepts
 
-pub use self::kinds::{PrimaryColor, SecondaryColor};
-pub use self::utils::mix;
+pub use self::kinds::{PrimaryColor, SecondaryColor, TertiaryColor};
+pub use self::utils::{mix, mix_ratio, mix_tertiary, Color};
 
 pub mod kinds {
     /// The primary colors according to the RYB color model.
@@ -21,11 +21,152 @@ pub mod kinds {
         Green,
         Purple,
     }
+
+    /// The tertiary colors, each a primary mixed with an adjacent secondary.
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    pub enum TertiaryColor {
+        RedOrange,
+        YellowOrange,
+        YellowGreen,
+        BlueGreen,
+        BluePurple,
+        RedPurple,
+    }
 }
 
 pub mod utils {
     use crate::kinds::*;
 
+    /// A color in the RYB model, with each component a fraction in `[0, 1]`.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Color {
+        pub red: f64,
+        pub yellow: f64,
+        pub blue: f64,
+    }
+
+    impl Color {
+        pub fn new(red: f64, yellow: f64, blue: f64) -> Self {
+            Self { red, yellow, blue }
+        }
+
+        /// Converts this RYB color to an RGB hex string (e.g. `#FF8000`) via
+        /// trilinear interpolation over the eight cube corners where pure
+        /// RYB combinations map to known RGB colors (white, red, yellow,
+        /// orange, blue, violet, green, black).
+        pub fn to_rgb_hex(&self) -> String {
+            const CORNERS: [[f64; 3]; 8] = [
+                [255.0, 255.0, 255.0], // red=0 yellow=0 blue=0 -> white
+                [255.0, 0.0, 0.0],     // red=1 yellow=0 blue=0 -> red
+                [255.0, 255.0, 0.0],   // red=0 yellow=1 blue=0 -> yellow
+                [255.0, 165.0, 0.0],   // red=1 yellow=1 blue=0 -> orange
+                [0.0, 0.0, 255.0],     // red=0 yellow=0 blue=1 -> blue
+                [128.0, 0.0, 128.0],   // red=1 yellow=0 blue=1 -> violet
+                [0.0, 128.0, 0.0],     // red=0 yellow=1 blue=1 -> green
+                [0.0, 0.0, 0.0],       // red=1 yellow=1 blue=1 -> black
+            ];
+
+            let red = self.red.clamp(0.0, 1.0);
+            let yellow = self.yellow.clamp(0.0, 1.0);
+            let blue = self.blue.clamp(0.0, 1.0);
+
+            let mut rgb = [0.0; 3];
+            for (channel, value) in rgb.iter_mut().enumerate() {
+                let c00 = lerp(CORNERS[0b000][channel], CORNERS[0b001][channel], red);
+                let c10 = lerp(CORNERS[0b010][channel], CORNERS[0b011][channel], red);
+                let c01 = lerp(CORNERS[0b100][channel], CORNERS[0b101][channel], red);
+                let c11 = lerp(CORNERS[0b110][channel], CORNERS[0b111][channel], red);
+
+                let c0 = lerp(c00, c10, yellow);
+                let c1 = lerp(c01, c11, yellow);
+
+                *value = lerp(c0, c1, blue);
+            }
+
+            format!(
+                "#{:02X}{:02X}{:02X}",
+                rgb[0].round() as u8,
+                rgb[1].round() as u8,
+                rgb[2].round() as u8
+            )
+        }
+    }
+
+    fn lerp(a: f64, b: f64, t: f64) -> f64 {
+        a + (b - a) * t
+    }
+
+    impl From<PrimaryColor> for Color {
+        fn from(primary: PrimaryColor) -> Self {
+            match primary {
+                PrimaryColor::Red => Color::new(1.0, 0.0, 0.0),
+                PrimaryColor::Yellow => Color::new(0.0, 1.0, 0.0),
+                PrimaryColor::Blue => Color::new(0.0, 0.0, 1.0),
+            }
+        }
+    }
+
+    impl From<SecondaryColor> for Color {
+        fn from(secondary: SecondaryColor) -> Self {
+            match secondary {
+                SecondaryColor::Orange => Color::new(0.5, 0.5, 0.0),
+                SecondaryColor::Green => Color::new(0.0, 0.5, 0.5),
+                SecondaryColor::Purple => Color::new(0.5, 0.0, 0.5),
+            }
+        }
+    }
+
+    impl From<TertiaryColor> for Color {
+        fn from(tertiary: TertiaryColor) -> Self {
+            let (primary, secondary) = tertiary.components();
+            mix_ratio(primary.into(), secondary.into(), 0.5)
+        }
+    }
+
+    impl TertiaryColor {
+        fn components(self) -> (PrimaryColor, SecondaryColor) {
+            match self {
+                TertiaryColor::RedOrange => (PrimaryColor::Red, SecondaryColor::Orange),
+                TertiaryColor::YellowOrange => (PrimaryColor::Yellow, SecondaryColor::Orange),
+                TertiaryColor::YellowGreen => (PrimaryColor::Yellow, SecondaryColor::Green),
+                TertiaryColor::BlueGreen => (PrimaryColor::Blue, SecondaryColor::Green),
+                TertiaryColor::BluePurple => (PrimaryColor::Blue, SecondaryColor::Purple),
+                TertiaryColor::RedPurple => (PrimaryColor::Red, SecondaryColor::Purple),
+            }
+        }
+    }
+
+    /// Linearly interpolates between two colors by `t`, clamped to `[0, 1]`.
+    pub fn mix_ratio(a: Color, b: Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        Color {
+            red: lerp(a.red, b.red, t),
+            yellow: lerp(a.yellow, b.yellow, t),
+            blue: lerp(a.blue, b.blue, t),
+        }
+    }
+
+    /// Mixes a primary color with an adjacent secondary to get the tertiary
+    /// color between them, or `None` if they aren't adjacent on the color
+    /// wheel (e.g. red and green).
+    pub fn mix_tertiary(
+        primary: PrimaryColor,
+        secondary: SecondaryColor,
+    ) -> Option<TertiaryColor> {
+        use PrimaryColor::*;
+        use SecondaryColor::*;
+
+        match (primary, secondary) {
+            (Red, Orange) => Some(TertiaryColor::RedOrange),
+            (Yellow, Orange) => Some(TertiaryColor::YellowOrange),
+            (Yellow, Green) => Some(TertiaryColor::YellowGreen),
+            (Blue, Green) => Some(TertiaryColor::BlueGreen),
+            (Blue, Purple) => Some(TertiaryColor::BluePurple),
+            (Red, Purple) => Some(TertiaryColor::RedPurple),
+            _ => None,
+        }
+    }
+
     /// Combines two primary colors in equal amounts to create a secondary color.
     ///
     /// Example:
@@ -35,22 +176,62 @@ pub mod utils {
     /// let primary_color_1 = PrimaryColor::Yellow;
     /// let primary_color_2 = PrimaryColor::Red;
     /// let mixture = mix(primary_color_1, primary_color_2);
-    /// assert_eq!(SecondaryColor::Orange, mixture)
+    /// assert_eq!(Ok(SecondaryColor::Orange), mixture)
     /// ```
-    ///
-    /// Panics:
-    /// if both the primary colors passed to it are the same.
-    pub fn mix(pc1: PrimaryColor, pc2: PrimaryColor) -> SecondaryColor {
+    pub fn mix(pc1: PrimaryColor, pc2: PrimaryColor) -> Result<SecondaryColor, &'static str> {
         if pc1 == pc2 {
-            panic!("you must mix two different primary colors to get a secondary color!")
+            return Err("you must mix two different primary colors to get a secondary color!");
         }
 
-        if pc1 == PrimaryColor::Blue && pc2 == PrimaryColor::Red {
+        Ok(if pc1 == PrimaryColor::Blue && pc2 == PrimaryColor::Red {
             SecondaryColor::Purple
         } else if pc1 == PrimaryColor::Blue && pc2 == PrimaryColor::Yellow {
             SecondaryColor::Green
         } else {
             SecondaryColor::Orange
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn mix_still_panics_free_on_same_color_input() {
+            assert!(mix(PrimaryColor::Red, PrimaryColor::Red).is_err());
+        }
+
+        #[test]
+        fn mix_ratio_interpolates_linearly() {
+            let red: Color = PrimaryColor::Red.into();
+            let blue: Color = PrimaryColor::Blue.into();
+
+            let midpoint = mix_ratio(red, blue, 0.5);
+            assert_eq!(midpoint, Color::new(0.5, 0.0, 0.5));
+        }
+
+        #[test]
+        fn mix_tertiary_only_combines_adjacent_colors() {
+            assert_eq!(
+                mix_tertiary(PrimaryColor::Red, SecondaryColor::Orange),
+                Some(TertiaryColor::RedOrange)
+            );
+            assert_eq!(mix_tertiary(PrimaryColor::Red, SecondaryColor::Green), None);
+        }
+
+        #[test]
+        fn pure_primary_colors_convert_to_expected_hex() {
+            let red: Color = PrimaryColor::Red.into();
+            assert_eq!(red.to_rgb_hex(), "#FF0000");
+
+            let blue: Color = PrimaryColor::Blue.into();
+            assert_eq!(blue.to_rgb_hex(), "#0000FF");
+        }
+
+        #[test]
+        fn no_color_is_white() {
+            let white = Color::new(0.0, 0.0, 0.0);
+            assert_eq!(white.to_rgb_hex(), "#FFFFFF");
         }
     }
 }
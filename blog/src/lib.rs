@@ -15,8 +15,8 @@ impl Post {
         self.content.push_str(text);
     }
 
-    pub fn content() -> &str {
-        ""
+    pub fn content(&self) -> &str {
+        self.state.as_ref().unwrap().content(self)
     }
 
     pub fn request_review(&mut self) {
@@ -24,25 +24,88 @@ impl Post {
             self.state = Some(s.request_review())
         }
     }
+
+    pub fn approve(&mut self) {
+        if let Some(s) = self.state.take() {
+            self.state = Some(s.approve())
+        }
+    }
+
+    pub fn reject(&mut self) {
+        if let Some(s) = self.state.take() {
+            self.state = Some(s.reject())
+        }
+    }
 }
 
 trait State {
     fn request_review(self: Box<Self>) -> Box<dyn State>;
+    fn approve(self: Box<Self>) -> Box<dyn State>;
+    fn reject(self: Box<Self>) -> Box<dyn State>;
+    fn content<'a>(&self, _post: &'a Post) -> &'a str {
+        ""
+    }
 }
 struct Draft {}
 
 impl State for Draft {
     fn request_review(self: Box<Self>) -> Box<dyn State> {
-        Box::new(PendingReview {})
+        Box::new(PendingReview { approvals: 0 })
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn State> {
+        self
     }
 }
 
-struct PendingReview {}
+struct PendingReview {
+    approvals: u8,
+}
 
 impl State for PendingReview {
     fn request_review(self: Box<Self>) -> Box<dyn State> {
         self
     }
+
+    fn approve(self: Box<Self>) -> Box<dyn State> {
+        // if already one approval is present, this is the second one
+        // so publish
+        if self.approvals == 1 {
+            return Box::new(Published {});
+        }
+
+        Box::new(PendingReview {
+            approvals: self.approvals + 1,
+        })
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn State> {
+        Box::new(Draft {})
+    }
+}
+
+struct Published {}
+
+impl State for Published {
+    fn request_review(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn content<'a>(&self, post: &'a Post) -> &'a str {
+        &post.content
+    }
 }
 
 #[cfg(test)]
@@ -60,6 +123,25 @@ mod blog {
         post.request_review();
         assert_eq!("", post.content());
 
+        post.approve();
+        assert_eq!("", post.content());
+
+        post.approve();
+        assert_eq!(content, post.content());
+    }
+
+    #[test]
+    fn reject_sends_a_post_back_to_draft() {
+        let mut post = Post::new();
+        let content = "I ate a salad for lunch today";
+
+        post.add_text(content);
+        post.request_review();
+        post.reject();
+        assert_eq!("", post.content());
+
+        post.request_review();
+        post.approve();
         post.approve();
         assert_eq!(content, post.content());
     }
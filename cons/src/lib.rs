@@ -1,12 +1,128 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
 
-// only allow single owner
+// generic, multiple-owner cons list with an optional weak parent back-pointer
 #[derive(Debug)]
-pub enum List {
-    Cons(i32, Box<List>),
+pub enum List<T> {
+    Cons(T, Rc<List<T>>, RefCell<Weak<List<T>>>),
     Nil,
 }
 
+pub struct ListIter<'a, T> {
+    current: Option<&'a List<T>>,
+}
+
+impl<'a, T> Iterator for ListIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.current.take() {
+            Some(List::Cons(value, next, _parent)) => {
+                self.current = Some(next);
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<T> List<T> {
+    /// Builds a `List<T>` from an iterator, with no nodes having a parent set.
+    pub fn from_items(items: impl IntoIterator<Item = T>) -> Rc<List<T>> {
+        let mut items: Vec<T> = items.into_iter().collect();
+        let mut list = Rc::new(List::Nil);
+        while let Some(item) = items.pop() {
+            list = Rc::new(List::Cons(item, list, RefCell::new(Weak::new())));
+        }
+        list
+    }
+
+    pub fn iter(&self) -> ListIter<'_, T> {
+        ListIter {
+            current: Some(self),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        matches!(self, List::Nil)
+    }
+
+    /// Returns a new list with `value` as its head and `self` as its tail,
+    /// leaving `self` untouched so other owners keep seeing the old list.
+    pub fn push_front(self: &Rc<Self>, value: T) -> Rc<List<T>> {
+        Rc::new(List::Cons(value, Rc::clone(self), RefCell::new(Weak::new())))
+    }
+
+    /// Upgrades this node's weak parent pointer, if one is set and still alive.
+    pub fn parent(&self) -> Option<Rc<List<T>>> {
+        match self {
+            List::Cons(_, _, parent) => parent.borrow().upgrade(),
+            List::Nil => None,
+        }
+    }
+
+    pub fn set_parent(&self, parent: &Rc<List<T>>) {
+        if let List::Cons(_, _, slot) = self {
+            *slot.borrow_mut() = Rc::downgrade(parent);
+        }
+    }
+
+    /// Walks the `Cons` chain with Floyd's tortoise-and-hare to detect
+    /// whether `next` pointers loop back on themselves.
+    pub fn detect_cycle(self: &Rc<Self>) -> bool {
+        fn step<T>(node: &Rc<List<T>>) -> Option<Rc<List<T>>> {
+            match node.as_ref() {
+                List::Cons(_, next, _) => Some(Rc::clone(next)),
+                List::Nil => None,
+            }
+        }
+
+        let mut slow = Rc::clone(self);
+        let mut fast = Rc::clone(self);
+
+        loop {
+            let Some(fast_next) = step(&fast) else {
+                return false;
+            };
+            let Some(fast_next_next) = step(&fast_next) else {
+                return false;
+            };
+            let Some(slow_next) = step(&slow) else {
+                return false;
+            };
+
+            slow = slow_next;
+            fast = fast_next_next;
+
+            if Rc::ptr_eq(&slow, &fast) {
+                return true;
+            }
+        }
+    }
+}
+
+thread_local! {
+    static ELEMENT_DROP_LOG: RefCell<Vec<String>> = RefCell::new(Vec::new());
+}
+
+/// Wraps a `List<T>` element to log its drop (in the order drops happen) to
+/// a thread-local, so a test can confirm the RAII cleanup a smart pointer
+/// guarantees actually runs head-to-tail as the list goes out of scope.
+#[derive(Debug)]
+pub struct DropLogged<T: std::fmt::Debug>(pub T);
+
+impl<T: std::fmt::Debug> Drop for DropLogged<T> {
+    fn drop(&mut self) {
+        ELEMENT_DROP_LOG.with(|log| log.borrow_mut().push(format!("{:?}", self.0)));
+    }
+}
+
 // allow multiple owners
 #[derive(Debug)]
 pub enum RcList {
@@ -35,3 +151,149 @@ impl UnsafeList {
         }
     }
 }
+
+/// Builds two `UnsafeList` nodes whose tails point at each other, printing
+/// the `Rc` strong counts before and after the cycle is wired up. Because
+/// each node holds a strong reference into the other, neither ever reaches
+/// a strong count of zero and both leak for the life of the program.
+pub fn make_cycle() -> (Rc<UnsafeList>, Rc<UnsafeList>) {
+    let a = Rc::new(UnsafeList::Cons(5, RefCell::new(Rc::new(UnsafeList::Nil))));
+    println!("a initial rc count = {}", Rc::strong_count(&a));
+
+    let b = Rc::new(UnsafeList::Cons(10, RefCell::new(Rc::clone(&a))));
+    println!("a rc count after b creation = {}", Rc::strong_count(&a));
+    println!("b initial rc count = {}", Rc::strong_count(&b));
+
+    if let Some(link) = a.tail() {
+        *link.borrow_mut() = Rc::clone(&b);
+    }
+
+    println!("b rc count after changing a = {}", Rc::strong_count(&b));
+    println!("a rc count after changing a = {}", Rc::strong_count(&a));
+
+    (a, b)
+}
+
+thread_local! {
+    static DROP_LOG: RefCell<Vec<i32>> = RefCell::new(Vec::new());
+}
+
+/// Same shape as [`UnsafeList`], but logs each node's value to a thread-local
+/// when dropped, so tests can observe whether `Drop` ever actually ran.
+#[derive(Debug)]
+pub enum DropLoggedList {
+    Cons(i32, RefCell<Rc<DropLoggedList>>),
+    Nil,
+}
+
+impl DropLoggedList {
+    pub fn tail(&self) -> Option<&RefCell<Rc<DropLoggedList>>> {
+        match self {
+            Self::Cons(_value, item) => Some(item),
+            Self::Nil => None,
+        }
+    }
+}
+
+impl Drop for DropLoggedList {
+    fn drop(&mut self) {
+        if let Self::Cons(value, _) = self {
+            DROP_LOG.with(|log| log.borrow_mut().push(*value));
+        }
+    }
+}
+
+/// Builds a `DropLoggedList` cycle identical in shape to [`make_cycle`].
+pub fn make_drop_logged_cycle() -> (Rc<DropLoggedList>, Rc<DropLoggedList>) {
+    let a = Rc::new(DropLoggedList::Cons(
+        5,
+        RefCell::new(Rc::new(DropLoggedList::Nil)),
+    ));
+    let b = Rc::new(DropLoggedList::Cons(10, RefCell::new(Rc::clone(&a))));
+
+    if let Some(link) = a.tail() {
+        *link.borrow_mut() = Rc::clone(&b);
+    }
+
+    (a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iterates_in_order() {
+        let list = List::from_items([1, 2, 3]);
+        let values: Vec<&i32> = list.iter().collect();
+        assert_eq!(values, vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn reports_len_and_emptiness() {
+        let list = List::from_items(["a", "b"]);
+        assert_eq!(list.len(), 2);
+        assert!(!list.is_empty());
+
+        let empty: Rc<List<i32>> = List::from_items(std::iter::empty());
+        assert_eq!(empty.len(), 0);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn acyclic_list_reports_no_cycle() {
+        let list = List::from_items([1, 2, 3]);
+        assert!(!list.detect_cycle());
+    }
+
+    #[test]
+    fn parent_back_pointer_can_be_set_and_upgraded() {
+        let parent = List::from_items([1]);
+        let child = List::from_items([2]);
+        child.set_parent(&parent);
+
+        assert!(Rc::ptr_eq(&child.parent().unwrap(), &parent));
+    }
+
+    #[test]
+    fn push_front_prepends_without_mutating_the_original_list() {
+        let tail = List::from_items([2, 3]);
+        let list = tail.push_front(1);
+
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(tail.iter().collect::<Vec<_>>(), vec![&2, &3]);
+    }
+
+    #[test]
+    fn list_drops_its_elements_head_to_tail() {
+        ELEMENT_DROP_LOG.with(|log| log.borrow_mut().clear());
+
+        {
+            let _list = List::from_items([DropLogged(1), DropLogged(2), DropLogged(3)]);
+        }
+
+        ELEMENT_DROP_LOG.with(|log| {
+            assert_eq!(*log.borrow(), vec!["1".to_string(), "2".to_string(), "3".to_string()]);
+        });
+    }
+
+    #[test]
+    fn make_cycle_leaves_each_node_with_an_outstanding_strong_reference() {
+        let (a, b) = make_cycle();
+
+        // each node is kept alive by the other's tail, plus the local binding.
+        assert_eq!(Rc::strong_count(&a), 2);
+        assert_eq!(Rc::strong_count(&b), 2);
+    }
+
+    #[test]
+    fn cyclic_list_leaks_and_is_never_dropped() {
+        DROP_LOG.with(|log| log.borrow_mut().clear());
+
+        {
+            let _cycle = make_drop_logged_cycle();
+        }
+
+        DROP_LOG.with(|log| assert!(log.borrow().is_empty()));
+    }
+}
@@ -1,15 +1,20 @@
 use std::{cell::RefCell, rc::Rc};
 
 use cons::{
-    List::{Cons, Nil},
+    List,
     RcList::Nil as RcNil,
     RcList::{self, Cons as RcCons},
     RefCellList::{self, Cons as RefCellCons, Nil as RefCellNil},
 };
 
 fn main() {
-    let list = Cons(1, Box::new(Cons(2, Box::new(Cons(3, Box::new(Nil))))));
+    let list = List::from_items([1, 2, 3]);
     println!("List: {list:?}");
+    println!(
+        "List has {} elements: {:?}",
+        list.len(),
+        list.iter().collect::<Vec<_>>()
+    );
 
     // define a new instance of RcList
     let a: RcList = RcCons(5, Rc::new(RcCons(10, Rc::new(RcNil))));
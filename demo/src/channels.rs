@@ -1,4 +1,167 @@
-use std::{sync::mpsc, thread, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::{mpsc, Arc, Barrier, Mutex},
+    thread,
+    time::Duration,
+};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+enum Message {
+    NewJob(Job),
+    Terminate,
+}
+
+/// A reusable work-dispatch pool built on a single `mpsc` channel shared by
+/// all workers behind an `Arc<Mutex<_>>`, generalizing the one-shot producer
+/// in [`test`] into a many-worker, many-job pattern.
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    sender: mpsc::Sender<Message>,
+}
+
+impl ThreadPool {
+    /// Create a new `ThreadPool` with `size` worker threads.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero.
+    pub fn new(size: usize) -> ThreadPool {
+        assert!(size > 0);
+
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let mut workers = Vec::with_capacity(size);
+        for id in 0..size {
+            workers.push(Worker::new(id, Arc::clone(&receiver)));
+        }
+
+        ThreadPool { workers, sender }
+    }
+
+    pub fn execute<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.sender
+            .send(Message::NewJob(Box::new(f)))
+            .expect("worker receiver dropped before pool");
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        for _ in &self.workers {
+            self.sender
+                .send(Message::Terminate)
+                .expect("worker receiver dropped before pool");
+        }
+
+        for worker in &mut self.workers {
+            println!("Shutting down worker {}", worker.id);
+            if let Some(thread) = worker.thread.take() {
+                thread.join().unwrap();
+            }
+        }
+    }
+}
+
+struct Worker {
+    id: usize,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl Worker {
+    fn new(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Message>>>) -> Worker {
+        let thread = thread::spawn(move || loop {
+            let message = receiver
+                .lock()
+                .expect("mutex poisoned")
+                .recv()
+                .expect("sender dropped before terminate was sent");
+
+            match message {
+                Message::NewJob(job) => {
+                    println!("Worker {id} got a job; executing...");
+                    job();
+                }
+                Message::Terminate => {
+                    println!("Worker {id} was told to terminate.");
+                    break;
+                }
+            }
+        });
+
+        Worker {
+            id,
+            thread: Some(thread),
+        }
+    }
+}
+
+pub type ItemId = u32;
+
+/// A request to the in-memory store spawned by [`launch`], carrying a
+/// one-shot `Sender` so the issuing thread gets a typed reply instead of
+/// firing the command and forgetting about it.
+pub enum Command {
+    Insert(String, mpsc::Sender<ItemId>),
+    Get(ItemId, mpsc::Sender<Option<String>>),
+}
+
+/// Spawns a server thread owning a `HashMap<ItemId, String>` store and
+/// returns a `Sender` clients can clone to submit [`Command`]s. Each command
+/// carries its own reply channel, so the server can answer many concurrent
+/// callers without a shared response stream.
+pub fn launch() -> mpsc::Sender<Command> {
+    let (sender, receiver) = mpsc::channel::<Command>();
+
+    thread::spawn(move || {
+        let mut store: HashMap<ItemId, String> = HashMap::new();
+        let mut next_id: ItemId = 0;
+
+        for command in receiver {
+            match command {
+                Command::Insert(item, reply) => {
+                    let id = next_id;
+                    next_id += 1;
+                    store.insert(id, item);
+                    let _ = reply.send(id);
+                }
+                Command::Get(id, reply) => {
+                    let _ = reply.send(store.get(&id).cloned());
+                }
+            }
+        }
+    });
+
+    sender
+}
+
+/// Spawns `n` worker threads that each "prepare" a value, wait at a shared
+/// `Barrier` so every worker finishes preparing before any of them proceeds,
+/// then "work" on the prepared value and send the result back. Returns the
+/// `n` results in the order they arrived.
+pub fn barrier_fanout(n: usize) -> Vec<usize> {
+    let barrier = Arc::new(Barrier::new(n));
+    let (sender, receiver) = mpsc::channel();
+
+    for id in 0..n {
+        let barrier = Arc::clone(&barrier);
+        let sender = sender.clone();
+
+        thread::spawn(move || {
+            let prepared = id + 1; // "prepare" phase
+            barrier.wait(); // wait for every worker to finish preparing
+            let result = prepared * 2; // "work" phase
+            sender.send(result).expect("receiver dropped before fanout completed");
+        });
+    }
+    drop(sender); // drop the original so the receiver loop below terminates
+
+    receiver.iter().collect()
+}
 
 pub fn test() {
     let (tx, rx) = mpsc::channel();
@@ -22,3 +185,64 @@ pub fn test() {
         println!("Got: {received}");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thread_pool_runs_every_submitted_job_before_shutdown() {
+        let pool = ThreadPool::new(4);
+        let counter = Arc::new(Mutex::new(0));
+
+        for _ in 0..10 {
+            let counter = Arc::clone(&counter);
+            pool.execute(move || {
+                *counter.lock().expect("mutex poisoned") += 1;
+            });
+        }
+
+        drop(pool); // blocks until every worker has joined
+
+        assert_eq!(*counter.lock().expect("mutex poisoned"), 10);
+    }
+
+    #[test]
+    fn command_server_round_trips_inserts_across_concurrent_clients() {
+        let server = launch();
+        let mut handles = Vec::new();
+
+        for i in 0..8 {
+            let server = server.clone();
+            handles.push(thread::spawn(move || {
+                let item = format!("item-{i}");
+
+                let (reply_tx, reply_rx) = mpsc::channel();
+                server
+                    .send(Command::Insert(item.clone(), reply_tx))
+                    .unwrap();
+                let id = reply_rx.recv().unwrap();
+
+                let (reply_tx, reply_rx) = mpsc::channel();
+                server.send(Command::Get(id, reply_tx)).unwrap();
+                assert_eq!(reply_rx.recv().unwrap(), Some(item));
+            }));
+        }
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn barrier_fanout_aggregates_exactly_n_results() {
+        let n = 5;
+        let results = barrier_fanout(n);
+
+        assert_eq!(results.len(), n);
+
+        let expected_sum: usize = (1..=n).map(|prepared| prepared * 2).sum();
+        let actual_sum: usize = results.iter().sum();
+        assert_eq!(actual_sum, expected_sum);
+    }
+}
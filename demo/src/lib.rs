@@ -2,6 +2,9 @@
 //!
 //! `Demo` is a collection of utilities that demonstrate `cargo`'s capabiltites.
 
+pub mod channels;
+pub mod nat;
+
 /// Adds one to the given number.
 ///
 /// # Examples
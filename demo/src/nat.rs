@@ -0,0 +1,86 @@
+use std::fmt;
+
+/// A Peano natural number: either zero, or the successor of another `Nat`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Nat {
+    Z,
+    S(Box<Nat>),
+}
+
+pub fn add(a: Nat, b: Nat) -> Nat {
+    match b {
+        Nat::Z => a,
+        Nat::S(b) => Nat::S(Box::new(add(a, *b))),
+    }
+}
+
+pub fn mul(a: Nat, b: Nat) -> Nat {
+    match b {
+        Nat::Z => Nat::Z,
+        Nat::S(b) => add(a.clone(), mul(a, *b)),
+    }
+}
+
+pub fn exp(a: Nat, b: Nat) -> Nat {
+    match b {
+        Nat::Z => Nat::S(Box::new(Nat::Z)),
+        Nat::S(b) => mul(a.clone(), exp(a, *b)),
+    }
+}
+
+impl From<u64> for Nat {
+    fn from(n: u64) -> Self {
+        let mut nat = Nat::Z;
+        for _ in 0..n {
+            nat = Nat::S(Box::new(nat));
+        }
+        nat
+    }
+}
+
+impl From<&Nat> for u64 {
+    // iterative so large values don't blow the stack walking the `S` chain
+    fn from(nat: &Nat) -> Self {
+        let mut count = 0;
+        let mut current = nat;
+        while let Nat::S(inner) = current {
+            count += 1;
+            current = inner;
+        }
+        count
+    }
+}
+
+impl fmt::Display for Nat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", u64::from(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds() {
+        let result: u64 = (&add(Nat::from(2), Nat::from(3))).into();
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn multiplies() {
+        let result: u64 = (&mul(Nat::from(4), Nat::from(5))).into();
+        assert_eq!(result, 20);
+    }
+
+    #[test]
+    fn exponentiates() {
+        let result: u64 = (&exp(Nat::from(2), Nat::from(10))).into();
+        assert_eq!(result, 1024);
+    }
+
+    #[test]
+    fn displays_as_decimal() {
+        assert_eq!(Nat::from(42).to_string(), "42");
+    }
+}
@@ -1,30 +1,70 @@
 use std::io;
 
-fn main() {
-    let mut last_two: [u128; 2] = [0, 1];
+/// A lazy, composable source of Fibonacci numbers.
+///
+/// `next()` computes the term two ahead with `checked_add` and returns `None`
+/// once that addition would overflow `u128`, rather than panicking, so the
+/// last two terms that still fit in `u128` are not yielded.
+struct Fibonacci {
+    curr: u128,
+    next: u128,
+}
+
+impl Default for Fibonacci {
+    fn default() -> Self {
+        Fibonacci { curr: 0, next: 1 }
+    }
+}
 
+impl Iterator for Fibonacci {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let curr = self.curr;
+        let new_next = self.curr.checked_add(self.next)?;
+
+        self.curr = self.next;
+        self.next = new_next;
+
+        Some(curr)
+    }
+}
+
+fn main() {
     let mut n = String::new();
 
     println!("generating nth fibonacci number, enter value of n: ");
     io::stdin().read_line(&mut n).expect("unable to read line");
 
-    // usize necessary for array indexing
     let n: usize = n
         .trim()
         .parse()
         .expect("please enter a valid positive number");
 
-    if n <= 2 {
-        println!("Fibonacci Number {n} = {}", last_two[n.saturating_sub(1)]);
-        return;
+    match Fibonacci::default().nth(n.saturating_sub(1)) {
+        Some(value) => println!("Fibonacci Number {n} = {value}"),
+        None => println!("Fibonacci Number {n} is beyond the u128 ceiling"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_terms() {
+        let terms: Vec<u128> = Fibonacci::default().take(8).collect();
+        assert_eq!(terms, vec![0, 1, 1, 2, 3, 5, 8, 13]);
     }
 
-    let mut new: u128;
-    for _ in 2..=n {
-        new = last_two[0] + last_two[1];
-        last_two[0] = last_two[1];
-        last_two[1] = new;
+    #[test]
+    fn composes_with_other_adapters() {
+        let sum: u128 = Fibonacci::default().take(10).sum();
+        assert_eq!(sum, 88);
     }
 
-    println!("Fibonacci Number {n} = {}", last_two[1]);
+    #[test]
+    fn terminates_near_the_u128_ceiling() {
+        assert!(Fibonacci::default().count() < usize::MAX);
+    }
 }
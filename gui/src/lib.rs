@@ -1,5 +1,28 @@
+/// An axis-aligned rectangle used to position and hit-test `Draw` components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    pub fn contains(&self, x: u32, y: u32) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+}
+
 pub trait Draw {
     fn draw(&self) -> String;
+
+    /// Where on the screen this component sits.
+    fn bounds(&self) -> Rect;
+
+    /// Higher layers are drawn (and hit-tested) after lower ones.
+    fn z_index(&self) -> i32 {
+        0
+    }
 }
 
 pub struct Screen {
@@ -7,17 +30,62 @@ pub struct Screen {
 }
 
 impl Screen {
+    fn components_by_z_index(&self) -> Vec<&dyn Draw> {
+        let mut components: Vec<&dyn Draw> = self.components.iter().map(|c| c.as_ref()).collect();
+        components.sort_by_key(|component| component.z_index());
+        components
+    }
+
     pub fn run(&self) -> Vec<String> {
-        let mut output = vec![];
-        for component in self.components.iter() {
-            output.push(component.draw());
+        self.components_by_z_index()
+            .into_iter()
+            .map(|component| component.draw())
+            .collect()
+    }
+
+    /// Composites every component's `draw()` output into a `canvas_h` x
+    /// `canvas_w` character grid at its `bounds()` position, clipping
+    /// anything that falls outside the canvas.
+    pub fn render_to_grid(&self, canvas_w: usize, canvas_h: usize) -> Vec<String> {
+        let mut grid = vec![vec![' '; canvas_w]; canvas_h];
+
+        for component in self.components_by_z_index() {
+            let bounds = component.bounds();
+
+            for (row_offset, line) in component.draw().lines().enumerate() {
+                let y = bounds.y as usize + row_offset;
+                if y >= canvas_h {
+                    break;
+                }
+
+                for (col_offset, ch) in line.chars().enumerate() {
+                    let x = bounds.x as usize + col_offset;
+                    if x >= canvas_w {
+                        break;
+                    }
+                    grid[y][x] = ch;
+                }
+            }
         }
 
-        output
+        grid.into_iter().map(|row| row.into_iter().collect()).collect()
+    }
+
+    /// Returns the index of the topmost component whose bounds contain
+    /// `(x, y)`, or `None` if nothing was hit.
+    pub fn dispatch_click(&self, x: u32, y: u32) -> Option<usize> {
+        self.components
+            .iter()
+            .enumerate()
+            .filter(|(_, component)| component.bounds().contains(x, y))
+            .max_by_key(|(_, component)| component.z_index())
+            .map(|(index, _)| index)
     }
 }
 
 pub struct Button {
+    pub x: u32,
+    pub y: u32,
     pub width: u32,
     pub height: u32,
     pub label: String,
@@ -27,6 +95,15 @@ impl Draw for Button {
     fn draw(&self) -> String {
         String::from("drawing a button")
     }
+
+    fn bounds(&self) -> Rect {
+        Rect {
+            x: self.x,
+            y: self.y,
+            width: self.width,
+            height: self.height,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -36,6 +113,8 @@ mod test {
     #[test]
     fn draws_a_button() {
         let button = Button {
+            x: 0,
+            y: 0,
             width: 10,
             height: 12,
             label: String::from("Test Button"),
@@ -63,6 +142,15 @@ mod test {
                     self.height, self.width, self.options
                 )
             }
+
+            fn bounds(&self) -> Rect {
+                Rect {
+                    x: 0,
+                    y: 0,
+                    width: self.width,
+                    height: self.height,
+                }
+            }
         }
 
         let width = 10;
@@ -106,11 +194,22 @@ mod test {
                     self.width, self.height, self.placeholder
                 )
             }
+
+            fn bounds(&self) -> Rect {
+                Rect {
+                    x: 0,
+                    y: 0,
+                    width: self.width,
+                    height: self.height,
+                }
+            }
         }
 
         let screen = Screen {
             components: vec![
                 Box::new(Button {
+                    x: 0,
+                    y: 0,
                     width: WIDTH,
                     height: HEIGHT,
                     label: String::from("test button label"),
@@ -128,4 +227,82 @@ mod test {
             format!("drawing a text field with width: {WIDTH}, height: {HEIGHT}, placeholder: {placeholder}"),
         ], screen.run());
     }
+
+    #[test]
+    fn higher_z_index_draws_last() {
+        struct Layer {
+            name: &'static str,
+            z: i32,
+        }
+
+        impl Draw for Layer {
+            fn draw(&self) -> String {
+                self.name.to_string()
+            }
+
+            fn bounds(&self) -> Rect {
+                Rect {
+                    x: 0,
+                    y: 0,
+                    width: 1,
+                    height: 1,
+                }
+            }
+
+            fn z_index(&self) -> i32 {
+                self.z
+            }
+        }
+
+        let screen = Screen {
+            components: vec![
+                Box::new(Layer { name: "front", z: 1 }),
+                Box::new(Layer { name: "back", z: -1 }),
+            ],
+        };
+
+        assert_eq!(vec!["back", "front"], screen.run());
+    }
+
+    #[test]
+    fn dispatch_click_hits_the_topmost_component() {
+        let screen = Screen {
+            components: vec![
+                Box::new(Button {
+                    x: 0,
+                    y: 0,
+                    width: 10,
+                    height: 10,
+                    label: String::from("under"),
+                }),
+                Box::new(Button {
+                    x: 0,
+                    y: 0,
+                    width: 10,
+                    height: 10,
+                    label: String::from("over"),
+                }),
+            ],
+        };
+
+        assert_eq!(Some(1), screen.dispatch_click(5, 5));
+        assert_eq!(None, screen.dispatch_click(50, 50));
+    }
+
+    #[test]
+    fn render_to_grid_composites_components_at_their_bounds() {
+        let screen = Screen {
+            components: vec![Box::new(Button {
+                x: 2,
+                y: 1,
+                width: 10,
+                height: 1,
+                label: String::from("hi"),
+            })],
+        };
+
+        let grid = screen.render_to_grid(20, 3);
+        assert_eq!(grid[1].chars().nth(2), Some('d'));
+        assert_eq!(grid[0], " ".repeat(20));
+    }
 }
@@ -1,18 +1,70 @@
 use std::{
+    any::Any,
+    cmp::Ordering,
+    collections::BinaryHeap,
+    panic::{self, AssertUnwindSafe},
     sync::{
-        mpsc::{self, Receiver, Sender},
-        Arc, Mutex,
+        atomic::{AtomicU64, Ordering as AtomicOrdering},
+        mpsc::{self, Receiver, RecvError},
+        Arc, Condvar, Mutex,
     },
     thread::{self, JoinHandle},
+    time::Duration,
 };
 
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// A queued [`Job`] ordered by `priority` first, then by `seq` (lower seq
+/// wins) so jobs of equal priority run in FIFO order.
+struct PrioritizedJob {
+    priority: u32,
+    seq: u64,
+    job: Job,
+}
+
+impl PartialEq for PrioritizedJob {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority && self.seq == other.seq
+    }
+}
+
+impl Eq for PrioritizedJob {}
+
+impl PartialOrd for PrioritizedJob {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PrioritizedJob {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap: higher priority should sort greater,
+        // and for equal priority the smaller (older) `seq` should sort
+        // greater so it pops first.
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+struct SchedulerState {
+    queue: BinaryHeap<PrioritizedJob>,
+    shutdown: bool,
+}
+
+struct Scheduler {
+    state: Mutex<SchedulerState>,
+    condvar: Condvar,
+}
+
 pub struct ThreadPool {
     workers: Vec<Worker>,
-    job_sender: Option<Sender<Job>>,
+    scheduler: Arc<Scheduler>,
+    next_seq: AtomicU64,
+    max_retries: u32,
+    backoff: Duration,
 }
 
-type Job = Box<dyn FnOnce() + Send + 'static>;
-
 impl ThreadPool {
     /// Create a new [`ThreadPool`].
     ///
@@ -24,37 +76,189 @@ impl ThreadPool {
     pub fn new(num_threads: usize) -> ThreadPool {
         assert!(num_threads != 0);
 
-        let (job_queue_tx, job_queue_rx) = mpsc::channel::<Job>();
+        let scheduler = Arc::new(Scheduler {
+            state: Mutex::new(SchedulerState {
+                queue: BinaryHeap::new(),
+                shutdown: false,
+            }),
+            condvar: Condvar::new(),
+        });
 
         let mut workers = Vec::with_capacity(num_threads);
-
-        let receiver = Arc::new(Mutex::new(job_queue_rx));
         for id in 0..num_threads {
-            workers.push(Worker::new(id, Arc::clone(&receiver)));
+            workers.push(Worker::new(id, Arc::clone(&scheduler)));
         }
 
         ThreadPool {
             workers,
-            job_sender: Some(job_queue_tx),
+            scheduler,
+            next_seq: AtomicU64::new(0),
+            max_retries: 0,
+            backoff: Duration::from_millis(50),
         }
     }
 
+    /// Configure the retry policy used by [`SyncExecutor::run_and_confirm`].
+    ///
+    /// `max_retries` is the number of additional attempts made after the
+    /// first one fails, waiting `backoff` between each attempt.
+    pub fn with_retry_policy(mut self, max_retries: u32, backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.backoff = backoff;
+        self
+    }
+
     pub fn execute<F>(&self, f: F)
     where
         F: FnOnce() + Send + 'static,
     {
-        let job = Box::new(f);
-        self.job_sender
-            .as_ref()
-            .expect("channel closed")
-            .send(job)
-            .unwrap();
+        self.execute_with_priority(0, f);
+    }
+
+    /// Like [`execute`](ThreadPool::execute), but `priority` jobs are
+    /// scheduled ahead of lower-priority ones. Jobs of equal priority run in
+    /// the order they were submitted.
+    pub fn execute_with_priority<F>(&self, priority: u32, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let seq = self.next_seq.fetch_add(1, AtomicOrdering::SeqCst);
+        let job = PrioritizedJob {
+            priority,
+            seq,
+            job: Box::new(f),
+        };
+
+        let mut state = self.scheduler.state.lock().expect("mutex poisoned");
+        state.queue.push(job);
+        drop(state);
+        self.scheduler.condvar.notify_one();
+    }
+
+    /// Like [`execute`](ThreadPool::execute), but returns a [`JobHandle`] that
+    /// yields the closure's result once a worker has run it.
+    pub fn execute_with_result<F, T>(&self, f: F) -> JobHandle<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let (result_sender, result_receiver) = mpsc::channel();
+
+        self.execute(move || {
+            // the receiving end may already be gone if the caller dropped
+            // the handle; that's fine, there's simply nowhere to report to.
+            let _ = result_sender.send(f());
+        });
+
+        JobHandle { result_receiver }
+    }
+
+    /// Detects any worker whose thread has died (e.g. from an abort-style
+    /// failure `catch_unwind` can't trap) and spawns a replacement with the
+    /// same id, keeping the configured thread count stable.
+    pub fn restart_dead_workers(&mut self) {
+        for worker in &mut self.workers {
+            let died = worker
+                .thread
+                .as_ref()
+                .is_some_and(JoinHandle::is_finished);
+
+            if died {
+                if let Some(thread) = worker.thread.take() {
+                    let _ = thread.join();
+                }
+                eprintln!("Worker {} died; spawning a replacement", worker.id);
+                *worker = Worker::new(worker.id, Arc::clone(&self.scheduler));
+            }
+        }
+    }
+}
+
+/// Fire-and-forget job submission.
+pub trait AsyncExecutor {
+    fn submit<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static;
+}
+
+/// Blocking job submission that waits for (and can retry) a result.
+pub trait SyncExecutor {
+    /// Runs `f` on the pool, retrying on `Err` up to the pool's configured
+    /// `max_retries`, and blocks until a result is ready.
+    fn run_and_confirm<F, T, E>(&self, f: F) -> Result<T, ExecError>
+    where
+        F: Fn() -> Result<T, E> + Send + 'static,
+        T: Send + 'static;
+}
+
+/// An error encountered by [`SyncExecutor::run_and_confirm`].
+#[derive(Debug)]
+pub enum ExecError {
+    /// `f` kept returning `Err`, even after exhausting all retries.
+    RetriesExhausted,
+    /// The worker running the job was lost before a result arrived.
+    ChannelClosed,
+}
+
+impl AsyncExecutor for ThreadPool {
+    fn submit<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.execute(f);
+    }
+}
+
+impl SyncExecutor for ThreadPool {
+    fn run_and_confirm<F, T, E>(&self, f: F) -> Result<T, ExecError>
+    where
+        F: Fn() -> Result<T, E> + Send + 'static,
+        T: Send + 'static,
+    {
+        let max_retries = self.max_retries;
+        let backoff = self.backoff;
+
+        let handle = self.execute_with_result(move || {
+            let mut attempts = 0;
+            loop {
+                match f() {
+                    Ok(value) => return Ok(value),
+                    Err(_) if attempts < max_retries => {
+                        attempts += 1;
+                        thread::sleep(backoff);
+                    }
+                    Err(_) => return Err(()),
+                }
+            }
+        });
+
+        match handle.join() {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(())) => Err(ExecError::RetriesExhausted),
+            Err(_) => Err(ExecError::ChannelClosed),
+        }
+    }
+}
+
+/// A handle to a job submitted via [`ThreadPool::execute_with_result`].
+pub struct JobHandle<T> {
+    result_receiver: Receiver<T>,
+}
+
+impl<T> JobHandle<T> {
+    /// Blocks until the job completes and returns its result.
+    pub fn join(self) -> Result<T, RecvError> {
+        self.result_receiver.recv()
     }
 }
 
 impl Drop for ThreadPool {
     fn drop(&mut self) {
-        drop(self.job_sender.take());
+        {
+            let mut state = self.scheduler.state.lock().expect("mutex poisoned");
+            state.shutdown = true;
+        }
+        self.scheduler.condvar.notify_all();
 
         for worker in &mut self.workers {
             println!("Shutting down worker {}", worker.id);
@@ -71,17 +275,33 @@ pub struct Worker {
 }
 
 impl Worker {
-    fn new(id: usize, receiver: Arc<Mutex<Receiver<Job>>>) -> Worker {
+    fn new(id: usize, scheduler: Arc<Scheduler>) -> Worker {
         let thread = thread::spawn(move || loop {
-            // assign separately to drop and release `MutexGuard` lock
-            let message = receiver.lock().expect("mutex poisoned").recv();
+            let mut state = scheduler.state.lock().expect("mutex poisoned");
+            while state.queue.is_empty() && !state.shutdown {
+                state = scheduler.condvar.wait(state).expect("mutex poisoned");
+            }
 
-            if let Ok(job) = message {
-                println!("Worker {id} got a job; executing...");
-                job();
-            } else {
-                println!("Shutting down thread for worker: {id}");
-                break;
+            let job = state.queue.pop();
+            drop(state);
+
+            match job {
+                Some(prioritized) => {
+                    println!("Worker {id} got a job; executing...");
+                    let job = prioritized.job;
+                    // isolate the job's panic so it can't take the whole
+                    // worker thread down with it
+                    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(job)) {
+                        eprintln!(
+                            "Worker {id} recovered from a panicking job: {}",
+                            panic_message(&payload)
+                        );
+                    }
+                }
+                None => {
+                    println!("Shutting down thread for worker: {id}");
+                    break;
+                }
             }
         });
         Worker {
@@ -90,3 +310,112 @@ impl Worker {
         }
     }
 }
+
+fn panic_message(payload: &Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn job_handle_join_returns_the_closures_result() {
+        let pool = ThreadPool::new(2);
+        let handle = pool.execute_with_result(|| 6 * 7);
+        assert_eq!(handle.join().unwrap(), 42);
+    }
+
+    #[test]
+    fn execute_with_priority_runs_higher_priority_jobs_first() {
+        let pool = ThreadPool::new(1);
+
+        // block the lone worker until every job below has been queued, so
+        // submission order can't race with the worker draining the queue.
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        pool.execute(move || release_rx.recv().unwrap());
+
+        let order = Arc::new(Mutex::new(Vec::new()));
+        for priority in [1, 5, 3] {
+            let order = Arc::clone(&order);
+            pool.execute_with_priority(priority, move || {
+                order.lock().expect("mutex poisoned").push(priority);
+            });
+        }
+
+        release_tx.send(()).unwrap();
+
+        // a default-priority (0) marker job is guaranteed to run last, since
+        // every priority above is higher than 0.
+        pool.execute_with_result(|| ()).join().unwrap();
+
+        assert_eq!(*order.lock().expect("mutex poisoned"), vec![5, 3, 1]);
+    }
+
+    #[test]
+    fn run_and_confirm_retries_until_the_closure_succeeds() {
+        let pool = ThreadPool::new(1).with_retry_policy(3, Duration::from_millis(1));
+        let attempts = Arc::new(AtomicU64::new(0));
+        let attempts_in_job = Arc::clone(&attempts);
+
+        let result = pool.run_and_confirm(move || {
+            let attempt = attempts_in_job.fetch_add(1, AtomicOrdering::SeqCst);
+            if attempt < 2 {
+                Err(())
+            } else {
+                Ok(attempt)
+            }
+        });
+
+        assert_eq!(result.unwrap(), 2);
+        assert_eq!(attempts.load(AtomicOrdering::SeqCst), 3);
+    }
+
+    #[test]
+    fn run_and_confirm_reports_retries_exhausted() {
+        let pool = ThreadPool::new(1).with_retry_policy(2, Duration::from_millis(1));
+
+        let result: Result<(), ExecError> = pool.run_and_confirm(|| Err::<(), ()>(()));
+
+        assert!(matches!(result, Err(ExecError::RetriesExhausted)));
+    }
+
+    #[test]
+    fn a_panicking_job_does_not_kill_the_worker() {
+        let pool = ThreadPool::new(1);
+
+        pool.execute(|| panic!("boom"));
+
+        // the same worker should still be alive to pick up the next job
+        let handle = pool.execute_with_result(|| 1 + 1);
+        assert_eq!(handle.join().unwrap(), 2);
+    }
+
+    #[test]
+    fn restart_dead_workers_replaces_a_dead_worker_with_the_same_id() {
+        let mut pool = ThreadPool::new(1);
+        let id = pool.workers[0].id;
+
+        // a thread that's already finished, simulating a dead worker
+        let dead_thread = thread::spawn(|| {});
+        while !dead_thread.is_finished() {
+            thread::yield_now();
+        }
+        pool.workers[0].thread = Some(dead_thread);
+
+        pool.restart_dead_workers();
+
+        assert_eq!(pool.workers[0].id, id);
+        assert!(!pool.workers[0]
+            .thread
+            .as_ref()
+            .expect("restarted worker has no thread")
+            .is_finished());
+    }
+}
@@ -35,6 +35,42 @@ fn test_my_own_vec() {
     println!("My own vec with no element is: {:?}", c);
 }
 
+/// Matches `$inp` against `$variant` and, if it matches, returns the
+/// contained value from the enclosing function. Falls through otherwise.
+macro_rules! early_return {
+    ($inp:expr, $variant:path) => {
+        match $inp {
+            $variant(x) => return x,
+            _ => {}
+        }
+    };
+}
+
+#[derive(Debug)]
+enum UsState {
+    Alabama,
+    Alaska,
+    Arizona,
+}
+
+#[derive(Debug)]
+enum Coin {
+    Penny,
+    Nickel,
+    Dime,
+    Quarter(UsState),
+}
+
+/// Returns the state of the first quarter found in `coins`, falling back to
+/// `UsState::Alabama` if none of the coins is a quarter.
+fn first_quarter_state(coins: Vec<Coin>) -> UsState {
+    for coin in coins {
+        early_return!(coin, Coin::Quarter);
+    }
+
+    UsState::Alabama
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,4 +80,16 @@ mod tests {
         test_vec_macro();
         test_my_own_vec();
     }
+
+    #[test]
+    fn early_return_bails_out_on_a_quarter() {
+        let coins = vec![Coin::Penny, Coin::Nickel, Coin::Quarter(UsState::Alaska)];
+        assert!(matches!(first_quarter_state(coins), UsState::Alaska));
+    }
+
+    #[test]
+    fn early_return_falls_through_with_no_matches() {
+        let coins = vec![Coin::Penny, Coin::Nickel, Coin::Dime];
+        assert!(matches!(first_quarter_state(coins), UsState::Alabama));
+    }
 }
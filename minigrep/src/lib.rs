@@ -1,35 +1,124 @@
-use std::{env, error::Error, fs};
+use std::{env, error::Error, fs, str::FromStr, sync::Arc, thread};
+
+use regex::Regex;
+
+/// The shape of the output `run` prints for each searched file.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OutputMode {
+    /// Print each matching line (the default).
+    Matching,
+    /// Print only the number of matching lines.
+    Count,
+    /// Print only the paths of files that contain a match.
+    FilesWithMatches,
+    /// Prepend the 1-based line number to each matching line.
+    LineNumbers,
+}
+
+impl FromStr for OutputMode {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "matching" => Ok(Self::Matching),
+            "count" => Ok(Self::Count),
+            "files-with-matches" => Ok(Self::FilesWithMatches),
+            "line-numbers" => Ok(Self::LineNumbers),
+            _ => Err("unknown output mode: expected one of `matching`, `count`, `files-with-matches`, `line-numbers`"),
+        }
+    }
+}
 
 pub struct Config {
     searchstring: String,
-    filepath: String,
+    filepaths: Vec<String>,
     ignore_case: bool,
+    use_regex: bool,
+    output_mode: OutputMode,
+    jobs: usize,
 }
 
 impl Config {
     pub fn build(mut args: impl Iterator<Item = String>) -> Result<Self, &'static str> {
         // first => program_name, second, third => arguments
         const IGNORE_CASE_ENV_KEY: &str = "IGNORE_CASE";
+        const USE_REGEX_ENV_KEY: &str = "USE_REGEX";
+        const MINIGREP_THREADS_ENV_KEY: &str = "MINIGREP_THREADS";
         let ignore_case = env::var(IGNORE_CASE_ENV_KEY).is_ok();
+        let mut use_regex = env::var(USE_REGEX_ENV_KEY).is_ok();
+        let mut output_mode = OutputMode::Matching;
+        let mut jobs: usize = env::var(MINIGREP_THREADS_ENV_KEY)
+            .ok()
+            .and_then(|jobs| jobs.parse().ok())
+            .unwrap_or(1);
 
         // ignore the program name
         args.next();
 
-        // get the search string
-        let searchstring = match args.next() {
+        // get the search string, consuming any leading flags first
+        let mut searchstring = match args.next() {
             Some(searchstring) => searchstring,
             None => return Err("didn't get a search string"),
         };
 
-        let filepath = match args.next() {
-            Some(filepath) => filepath,
-            None => return Err("didn't get a filepath"),
-        };
+        loop {
+            match searchstring.as_str() {
+                "--regex" | "-E" => {
+                    use_regex = true;
+                }
+                "--mode" => {
+                    let mode = match args.next() {
+                        Some(mode) => mode,
+                        None => return Err("--mode requires a value"),
+                    };
+                    output_mode = mode.parse()?;
+                }
+                "--jobs" => {
+                    let count = match args.next() {
+                        Some(count) => count,
+                        None => return Err("--jobs requires a value"),
+                    };
+                    jobs = count.parse().map_err(|_| "--jobs expects a positive integer")?;
+                }
+                _ => break,
+            }
+
+            searchstring = match args.next() {
+                Some(searchstring) => searchstring,
+                None => return Err("didn't get a search string"),
+            };
+        }
+
+        let patterns: Vec<String> = args.collect();
+        if patterns.is_empty() {
+            return Err("didn't get a filepath");
+        }
+
+        let mut filepaths = Vec::new();
+        for pattern in patterns {
+            match glob::glob(&pattern) {
+                Ok(paths) => {
+                    let mut matched = false;
+                    for entry in paths.flatten() {
+                        filepaths.push(entry.display().to_string());
+                        matched = true;
+                    }
+                    // not a glob pattern (or nothing matched): treat it as a literal path
+                    if !matched {
+                        filepaths.push(pattern);
+                    }
+                }
+                Err(_) => filepaths.push(pattern),
+            }
+        }
 
         Ok(Self {
             searchstring,
-            filepath,
+            filepaths,
             ignore_case,
+            use_regex,
+            output_mode,
+            jobs,
         })
     }
 }
@@ -59,16 +148,174 @@ fn case_insensitive_search<'a>(searchstring: &'a str, contents: &'a str) -> Vec<
     result
 }
 
-pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
-    let contents = fs::read_to_string(config.filepath)?;
+fn regex_search<'a>(re: &Regex, contents: &'a str) -> Vec<&'a str> {
+    let mut result: Vec<&str> = vec![];
+
+    for line in contents.lines() {
+        if re.is_match(line) {
+            result.push(line);
+        }
+    }
 
-    let matching_lines = match config.ignore_case {
-        true => case_insensitive_search(&config.searchstring, &contents),
-        false => search(&config.searchstring, &contents),
+    result
+}
+
+fn line_matches(line: &str, searchstring: &str, ignore_case: bool, re: Option<&Regex>) -> bool {
+    match re {
+        Some(re) => re.is_match(line),
+        None if ignore_case => line.to_lowercase().contains(&searchstring.to_lowercase()),
+        None => line.contains(searchstring),
+    }
+}
+
+/// Splits `lines` into `jobs` roughly equal ranges and scans each range on
+/// its own thread, merging the matches back in original line order.
+/// Falls back to a single-threaded scan when `jobs <= 1`.
+fn parallel_search(
+    lines: Vec<String>,
+    searchstring: &str,
+    ignore_case: bool,
+    re: Option<&Regex>,
+    jobs: usize,
+) -> Vec<(usize, String)> {
+    if jobs <= 1 || lines.len() < jobs {
+        return lines
+            .into_iter()
+            .enumerate()
+            .filter(|(_, line)| line_matches(line, searchstring, ignore_case, re))
+            .collect();
+    }
+
+    let searchstring = Arc::new(searchstring.to_string());
+    let re = re.cloned().map(Arc::new);
+    let chunk_size = lines.len().div_ceil(jobs);
+
+    let mut handles = Vec::new();
+    let mut offset = 0;
+    for chunk in lines.chunks(chunk_size) {
+        let chunk = chunk.to_vec();
+        let chunk_offset = offset;
+        offset += chunk.len();
+        let searchstring = Arc::clone(&searchstring);
+        let re = re.clone();
+
+        handles.push(thread::spawn(move || {
+            chunk
+                .into_iter()
+                .enumerate()
+                .filter(|(_, line)| line_matches(line, &searchstring, ignore_case, re.as_deref()))
+                .map(|(i, line)| (chunk_offset + i, line))
+                .collect::<Vec<_>>()
+        }));
+    }
+
+    let mut result: Vec<(usize, String)> = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().expect("worker thread panicked"))
+        .collect();
+    result.sort_by_key(|(line_number, _)| *line_number);
+    result
+}
+
+pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
+    let re = if config.use_regex {
+        let pattern = match config.ignore_case {
+            true => format!("(?i){}", config.searchstring),
+            false => config.searchstring.clone(),
+        };
+        Some(Regex::new(&pattern)?)
+    } else {
+        None
     };
 
-    for matching_line in matching_lines {
-        println!("{matching_line}");
+    let multiple_files = config.filepaths.len() > 1;
+
+    for filepath in &config.filepaths {
+        let contents = match fs::read_to_string(filepath) {
+            Ok(contents) => contents,
+            Err(e) => {
+                eprintln!("Problem reading {filepath}: {e}");
+                continue;
+            }
+        };
+
+        if config.jobs > 1 {
+            let lines: Vec<String> = contents.lines().map(String::from).collect();
+            let matches = parallel_search(
+                lines,
+                &config.searchstring,
+                config.ignore_case,
+                re.as_ref(),
+                config.jobs,
+            );
+
+            match config.output_mode {
+                OutputMode::Count => println!("{filepath}: {}", matches.len()),
+                OutputMode::FilesWithMatches => {
+                    if !matches.is_empty() {
+                        println!("{filepath}");
+                    }
+                }
+                OutputMode::LineNumbers => {
+                    for (line_number, matching_line) in &matches {
+                        if multiple_files {
+                            println!("{filepath}:{}:{matching_line}", line_number + 1);
+                        } else {
+                            println!("{}:{matching_line}", line_number + 1);
+                        }
+                    }
+                }
+                OutputMode::Matching => {
+                    for (_, matching_line) in &matches {
+                        if multiple_files {
+                            println!("{filepath}:{matching_line}");
+                        } else {
+                            println!("{matching_line}");
+                        }
+                    }
+                }
+            }
+            continue;
+        }
+
+        let matching_lines = match &re {
+            Some(re) => regex_search(re, &contents),
+            None => match config.ignore_case {
+                true => case_insensitive_search(&config.searchstring, &contents),
+                false => search(&config.searchstring, &contents),
+            },
+        };
+
+        match config.output_mode {
+            OutputMode::Count => println!("{filepath}: {}", matching_lines.len()),
+            OutputMode::FilesWithMatches => {
+                if !matching_lines.is_empty() {
+                    println!("{filepath}");
+                }
+            }
+            OutputMode::LineNumbers => {
+                for (line_number, matching_line) in contents
+                    .lines()
+                    .enumerate()
+                    .filter(|(_, line)| matching_lines.contains(line))
+                {
+                    if multiple_files {
+                        println!("{filepath}:{}:{matching_line}", line_number + 1);
+                    } else {
+                        println!("{}:{matching_line}", line_number + 1);
+                    }
+                }
+            }
+            OutputMode::Matching => {
+                for matching_line in matching_lines {
+                    if multiple_files {
+                        println!("{filepath}:{matching_line}");
+                    } else {
+                        println!("{matching_line}");
+                    }
+                }
+            }
+        }
     }
 
     Ok(())
@@ -78,6 +325,43 @@ pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn output_mode_parses_known_names() {
+        assert_eq!(Ok(OutputMode::Matching), "matching".parse());
+        assert_eq!(Ok(OutputMode::Count), "count".parse());
+        assert_eq!(Ok(OutputMode::FilesWithMatches), "files-with-matches".parse());
+        assert_eq!(Ok(OutputMode::LineNumbers), "line-numbers".parse());
+    }
+
+    #[test]
+    fn output_mode_rejects_unknown_names() {
+        let result: Result<OutputMode, _> = "nonsense".parse();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parallel_search_matches_sequential_search_regardless_of_thread_count() {
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three
+Duct tape.
+another line
+yet another duct";
+        let lines: Vec<String> = contents.lines().map(String::from).collect();
+        let expected: Vec<(usize, String)> = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| line.to_lowercase().contains("duct"))
+            .map(|(i, line)| (i, line.to_string()))
+            .collect();
+
+        for jobs in [1, 2, 3, 4] {
+            let result = parallel_search(lines.clone(), "duct", true, None, jobs);
+            assert_eq!(result, expected, "mismatch with jobs = {jobs}");
+        }
+    }
+
     #[test]
     fn one_result() {
         let searchstring = "duct";
@@ -107,6 +391,33 @@ Duct tape.";
         );
     }
 
+    #[test]
+    fn regex_match() {
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three
+Duct tape.";
+
+        let re = Regex::new(r"^[A-Z]\w+:").unwrap();
+        assert_eq!(vec!["Rust:"], regex_search(&re, contents));
+    }
+
+    #[test]
+    fn regex_match_case_insensitive() {
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three
+Duct tape.";
+
+        let re = Regex::new(r"(?i)duct").unwrap();
+        assert_eq!(
+            vec!["safe, fast, productive.", "Duct tape."],
+            regex_search(&re, contents)
+        );
+    }
+
     #[test]
     fn case_insensitive() {
         let searchstring = "duct";
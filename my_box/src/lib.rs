@@ -1,4 +1,4 @@
-use std::ops::Deref;
+use std::ops::{Deref, DerefMut};
 
 struct MyBox<T>(T);
 
@@ -16,6 +16,12 @@ impl<T> Deref for MyBox<T> {
     }
 }
 
+impl<T> DerefMut for MyBox<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 struct CustomSmartPointer {
     data: String,
 }
@@ -30,6 +36,10 @@ fn hello(name: &str) -> String {
     format!("Hello, {name}")
 }
 
+fn shout(s: &mut String) {
+    s.push_str("!!!");
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -74,6 +84,17 @@ mod test {
         assert_eq!("Hello, again", hello(&((*n).deref())[..])); // (*n).deref() = &String
     }
 
+    #[test]
+    fn deref_mut_allows_mutation_through_mybox() {
+        let mut m = MyBox::new(String::from("hi"));
+        shout(&mut m); // &mut MyBox<String> coerces to &mut String
+        assert_eq!("hi!!!", *m);
+
+        // explicit coercion, mirroring the immutable case above
+        shout(&mut (*m));
+        assert_eq!("hi!!!!!!", *m);
+    }
+
     #[test]
     fn drop_test() {
         let _c = CustomSmartPointer {
@@ -10,6 +10,27 @@ pub struct Node {
     children: RefCell<Vec<Rc<Node>>>,
 }
 
+impl Node {
+    pub fn new(value: i32) -> Rc<Node> {
+        Rc::new(Node {
+            value,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(Vec::new()),
+        })
+    }
+
+    /// Attaches `child` under `parent`, setting `child`'s weak back-pointer.
+    pub fn add_child(parent: &Rc<Node>, child: &Rc<Node>) {
+        *child.parent.borrow_mut() = Rc::downgrade(parent);
+        parent.children.borrow_mut().push(Rc::clone(child));
+    }
+
+    /// Upgrades this node's weak parent pointer, if one is set and still alive.
+    pub fn parent(&self) -> Option<Rc<Node>> {
+        self.parent.borrow().upgrade()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -62,4 +83,26 @@ mod test {
             Rc::weak_count(&leaf)
         );
     }
+
+    #[test]
+    fn parent_pointer_upgrades_and_counts_are_correct_across_scope_exit() {
+        let leaf = Node::new(3);
+        assert_eq!(Rc::strong_count(&leaf), 1);
+        assert_eq!(Rc::weak_count(&leaf), 0);
+        assert!(leaf.parent().is_none());
+
+        {
+            let branch = Node::new(5);
+            Node::add_child(&branch, &leaf);
+
+            assert!(Rc::ptr_eq(&leaf.parent().unwrap(), &branch));
+            assert_eq!(Rc::strong_count(&branch), 1);
+            assert_eq!(Rc::weak_count(&branch), 1);
+            assert_eq!(Rc::strong_count(&leaf), 2);
+        }
+
+        // `branch` is gone; the leaf's weak parent pointer can no longer upgrade.
+        assert!(leaf.parent().is_none());
+        assert_eq!(Rc::strong_count(&leaf), 1);
+    }
 }